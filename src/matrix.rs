@@ -1,7 +1,7 @@
 use std::fmt;
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Sub};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Matrix<T> {
     data: Vec<T>,
     rows: usize,
@@ -42,6 +42,37 @@ impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
     }
 }
 
+impl<T> Matrix<T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |i| (0..cols).map(move |j| (i, j)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    pub fn row(&self, i: usize) -> impl Iterator<Item = &T> {
+        self.data[i * self.cols..(i + 1) * self.cols].iter()
+    }
+
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> {
+        self.data[j..].iter().step_by(self.cols)
+    }
+}
+
 impl<T> Index<(usize, usize)> for Matrix<T> {
     type Output = T;
 
@@ -63,10 +94,22 @@ impl<T: Add<Output = T> + Copy + Default> Add for Matrix<T> {
         assert!(self.rows == rhs.rows && self.cols == rhs.cols);
 
         let mut res = Matrix::<T>::new(self.rows, self.cols);
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                res[(i, j)] = self[(i, j)] + rhs[(i, j)];
-            }
+        for (i, j) in self.indices() {
+            res[(i, j)] = self[(i, j)] + rhs[(i, j)];
+        }
+        res
+    }
+}
+
+impl<T: Sub<Output = T> + Copy + Default> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+
+        let mut res = Matrix::<T>::new(self.rows, self.cols);
+        for (i, j) in self.indices() {
+            res[(i, j)] = self[(i, j)] - rhs[(i, j)];
         }
         res
     }
@@ -79,10 +122,10 @@ impl<T: AddAssign + Copy + Default + Mul<Output = T>> Mul for Matrix<T> {
         assert!(self.cols == rhs.rows);
 
         let mut res = Matrix::<T>::new(self.rows, rhs.cols);
-        for k in 0..self.cols {
-            for i in 0..self.rows {
-                for j in 0..rhs.cols {
-                    res[(i, j)] += self[(i, k)] * rhs[(k, j)];
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                for (a, b) in self.row(i).zip(rhs.col(j)) {
+                    res[(i, j)] += *a * *b;
                 }
             }
         }
@@ -90,6 +133,305 @@ impl<T: AddAssign + Copy + Default + Mul<Output = T>> Mul for Matrix<T> {
     }
 }
 
+impl<T: Copy + Default> Matrix<T> {
+    pub fn minor(&self, row: usize, col: usize) -> Matrix<T> {
+        assert!(row < self.rows && col < self.cols);
+
+        let mut res = Matrix::<T>::new(self.rows - 1, self.cols - 1);
+        let mut ri = 0;
+        for i in 0..self.rows {
+            if i == row {
+                continue;
+            }
+            let mut rj = 0;
+            for j in 0..self.cols {
+                if j == col {
+                    continue;
+                }
+                res[(ri, rj)] = self[(i, j)];
+                rj += 1;
+            }
+            ri += 1;
+        }
+        res
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut res = Matrix::<T>::new(self.cols, self.rows);
+        for (i, j) in self.indices() {
+            res[(j, i)] = self[(i, j)];
+        }
+        res
+    }
+}
+
+const STRASSEN_THRESHOLD: usize = 64;
+
+impl<T: AddAssign + Copy + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T>>
+    Matrix<T>
+{
+    fn block(
+        &self,
+        row_start: usize,
+        row_end: usize,
+        col_start: usize,
+        col_end: usize,
+    ) -> Matrix<T> {
+        let mut res = Matrix::<T>::new(row_end - row_start, col_end - col_start);
+        for i in row_start..row_end {
+            for j in col_start..col_end {
+                res[(i - row_start, j - col_start)] = self[(i, j)];
+            }
+        }
+        res
+    }
+
+    fn pad_to(&self, n: usize) -> Matrix<T> {
+        let mut res = Matrix::<T>::new(n, n);
+        for (i, j) in self.indices() {
+            res[(i, j)] = self[(i, j)];
+        }
+        res
+    }
+
+    fn crop(&self, rows: usize, cols: usize) -> Matrix<T> {
+        let mut res = Matrix::<T>::new(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                res[(i, j)] = self[(i, j)];
+            }
+        }
+        res
+    }
+
+    pub fn strassen_mul(self, rhs: Self) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows);
+
+        let out_rows = self.rows;
+        let out_cols = rhs.cols;
+        if out_rows.max(self.cols).max(rhs.cols) < STRASSEN_THRESHOLD {
+            return self * rhs;
+        }
+
+        let n = [self.rows, self.cols, rhs.rows, rhs.cols]
+            .into_iter()
+            .max()
+            .unwrap()
+            .next_power_of_two();
+        let a = self.pad_to(n);
+        let b = rhs.pad_to(n);
+        let half = n / 2;
+
+        let a11 = a.block(0, half, 0, half);
+        let a12 = a.block(0, half, half, n);
+        let a21 = a.block(half, n, 0, half);
+        let a22 = a.block(half, n, half, n);
+        let b11 = b.block(0, half, 0, half);
+        let b12 = b.block(0, half, half, n);
+        let b21 = b.block(half, n, 0, half);
+        let b22 = b.block(half, n, half, n);
+
+        let m1 = (a11.clone() + a22.clone()).strassen_mul(b11.clone() + b22.clone());
+        let m2 = (a21.clone() + a22.clone()).strassen_mul(b11.clone());
+        let m3 = a11.clone().strassen_mul(b12.clone() - b22.clone());
+        let m4 = a22.clone().strassen_mul(b21.clone() - b11.clone());
+        let m5 = (a11.clone() + a12.clone()).strassen_mul(b22.clone());
+        let m6 = (a21.clone() - a11.clone()).strassen_mul(b11.clone() + b12.clone());
+        let m7 = (a12.clone() - a22.clone()).strassen_mul(b21.clone() + b22.clone());
+
+        let c11 = m1.clone() + m4.clone() - m5.clone() + m7;
+        let c12 = m3.clone() + m5;
+        let c21 = m2.clone() + m4;
+        let c22 = m1 - m2 + m3 + m6;
+
+        let mut combined = Matrix::<T>::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                combined[(i, j)] = if i < half && j < half {
+                    c11[(i, j)]
+                } else if i < half {
+                    c12[(i, j - half)]
+                } else if j < half {
+                    c21[(i - half, j)]
+                } else {
+                    c22[(i - half, j - half)]
+                };
+            }
+        }
+
+        combined.crop(out_rows, out_cols)
+    }
+}
+
+impl<T: Copy + Default + Mul<Output = T> + AddAssign + From<u8>> Matrix<T> {
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut res = Matrix::<T>::new(n, n);
+        for i in 0..n {
+            res[(i, i)] = T::from(1);
+        }
+        res
+    }
+
+    pub fn pow(self, mut exp: u64) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols, "pow requires a square matrix");
+
+        let mut base = self;
+        let mut acc = Matrix::<T>::identity(base.rows);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base.clone();
+            }
+            base = base.clone() * base;
+            exp >>= 1;
+        }
+        acc
+    }
+}
+
+const DETERMINANT_COFACTOR_THRESHOLD: usize = 4;
+
+fn abs<T: Copy + Default + PartialOrd + Sub<Output = T>>(v: T) -> T {
+    if v < T::default() {
+        T::default() - v
+    } else {
+        v
+    }
+}
+
+/// One step of Gaussian elimination with partial pivoting: picks the
+/// largest-magnitude pivot for `col` among `rows[col..]`, swaps it into place,
+/// then eliminates `col` out of every row yielded by `targets` (rows equal to
+/// `col` are skipped automatically). Returns whether a swap occurred, or
+/// `None` if no usable (non-zero) pivot exists.
+pub(crate) fn eliminate_column<T>(
+    rows: &mut [Vec<T>],
+    col: usize,
+    targets: impl Iterator<Item = usize>,
+) -> Option<bool>
+where
+    T: Copy + Default + PartialOrd + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let n = rows.len();
+    let pivot_row = (col..n)
+        .max_by(|&r1, &r2| abs(rows[r1][col]).partial_cmp(&abs(rows[r2][col])).unwrap())
+        .unwrap();
+    if rows[pivot_row][col] == T::default() {
+        return None;
+    }
+    let swapped = pivot_row != col;
+    if swapped {
+        rows.swap(col, pivot_row);
+    }
+
+    for row in targets {
+        if row == col {
+            continue;
+        }
+        let factor = rows[row][col] / rows[col][col];
+        if factor == T::default() {
+            continue;
+        }
+        let (pivot_row, other_row) = if row < col {
+            let (head, tail) = rows.split_at_mut(col);
+            (&tail[0], &mut head[row])
+        } else {
+            let (head, tail) = rows.split_at_mut(row);
+            (&head[col], &mut tail[0])
+        };
+        for (o, p) in other_row.iter_mut().zip(pivot_row.iter()) {
+            *o = *o - factor * *p;
+        }
+    }
+
+    Some(swapped)
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + Default
+        + PartialOrd
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    pub fn determinant(&self) -> T {
+        assert_eq!(self.rows, self.cols, "determinant requires a square matrix");
+
+        if self.rows < DETERMINANT_COFACTOR_THRESHOLD {
+            self.determinant_cofactor()
+        } else {
+            self.determinant_elimination()
+        }
+    }
+
+    fn determinant_cofactor(&self) -> T {
+        match self.rows {
+            1 => self[(0, 0)],
+            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
+            n => {
+                let mut det = T::default();
+                let mut sign = T::from(1);
+                for j in 0..n {
+                    det = det + sign * self[(0, j)] * self.minor(0, j).determinant_cofactor();
+                    sign = T::default() - sign;
+                }
+                det
+            }
+        }
+    }
+
+    fn determinant_elimination(&self) -> T {
+        let n = self.rows;
+        let mut a: Vec<Vec<T>> = (0..n)
+            .map(|i| (0..n).map(|j| self[(i, j)]).collect())
+            .collect();
+        let mut sign = T::from(1);
+
+        for col in 0..n {
+            match eliminate_column(&mut a, col, (col + 1)..n) {
+                Some(true) => sign = T::default() - sign,
+                Some(false) => {}
+                None => return T::default(),
+            }
+        }
+
+        a.iter()
+            .enumerate()
+            .fold(sign, |det, (i, row)| det * row[i])
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        assert_eq!(self.rows, self.cols, "inverse requires a square matrix");
+
+        let n = self.rows;
+        let mut aug: Vec<Vec<T>> = (0..n)
+            .map(|i| {
+                let mut row: Vec<T> = (0..n).map(|j| self[(i, j)]).collect();
+                row.extend((0..n).map(|j| if i == j { T::from(1) } else { T::default() }));
+                row
+            })
+            .collect();
+
+        for col in 0..n {
+            eliminate_column(&mut aug, col, 0..n)?;
+
+            let pivot = aug[col][col];
+            aug[col].iter_mut().for_each(|v| *v = *v / pivot);
+        }
+
+        let mut res = Matrix::<T>::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                res[(i, j)] = aug[i][n + j];
+            }
+        }
+        Some(res)
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for i in 0..self.rows {
@@ -129,4 +471,147 @@ mod tests {
 
         assert_eq!(m1 * m2, matrix![[21, 24, 27], [47, 54, 61]])
     }
+
+    #[test]
+    fn indices_are_row_major() {
+        let m = matrix![[1, 2], [3, 4]];
+
+        assert_eq!(
+            m.indices().collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+        )
+    }
+
+    #[test]
+    fn iter_yields_entries_in_row_major_order() {
+        let m = matrix![[1, 2], [3, 4]];
+
+        assert_eq!(m.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4])
+    }
+
+    #[test]
+    fn iter_mut_allows_in_place_updates() {
+        let mut m = matrix![[1, 2], [3, 4]];
+        m.iter_mut().for_each(|v| *v *= 10);
+
+        assert_eq!(m, matrix![[10, 20], [30, 40]])
+    }
+
+    #[test]
+    fn row_and_col_iterate_their_slice() {
+        let m = matrix![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(m.row(1).collect::<Vec<_>>(), vec![&4, &5, &6]);
+        assert_eq!(m.col(1).collect::<Vec<_>>(), vec![&2, &5]);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_cols() {
+        let m = matrix![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(m.transpose(), matrix![[1, 4], [2, 5], [3, 6]])
+    }
+
+    #[test]
+    fn strassen_mul_matches_naive_mul_below_threshold() {
+        let m1 = matrix![[1, 2], [3, 4]];
+        let m2 = matrix![[5, 6, 7], [8, 9, 10]];
+
+        assert_eq!(m1.strassen_mul(m2), matrix![[21, 24, 27], [47, 54, 61]])
+    }
+
+    #[test]
+    fn strassen_mul_handles_non_power_of_two_sizes_above_threshold() {
+        let n = 65;
+        let a = Matrix::try_from(
+            (0..n)
+                .map(|i| (0..n).map(|j| (i + j) as i64).collect())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let b = Matrix::try_from(
+            (0..n)
+                .map(|i| (0..n).map(|j| (i * j) as i64).collect())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        assert_eq!(a.clone().strassen_mul(b.clone()), a * b)
+    }
+
+    #[test]
+    fn identity_has_ones_on_the_diagonal() {
+        assert_eq!(
+            Matrix::<i32>::identity(3),
+            matrix![[1, 0, 0], [0, 1, 0], [0, 0, 1]]
+        )
+    }
+
+    #[test]
+    fn pow_zero_is_identity() {
+        let m = matrix![[1, 2], [3, 4]];
+
+        assert_eq!(m.pow(0), Matrix::<i32>::identity(2))
+    }
+
+    #[test]
+    fn pow_matches_repeated_multiplication() {
+        let m = matrix![[1, 1], [0, 1]];
+
+        assert_eq!(m.pow(5), matrix![[1, 5], [0, 1]])
+    }
+
+    #[test]
+    fn minor_removes_row_and_col() {
+        let m = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+        assert_eq!(m.minor(1, 1), matrix![[1, 3], [7, 9]])
+    }
+
+    #[test]
+    fn determinant_small() {
+        let m = matrix![[1.0, 2.0], [3.0, 4.0]];
+
+        assert_eq!(m.determinant(), -2.0)
+    }
+
+    #[test]
+    fn determinant_via_elimination() {
+        let m = Matrix::try_from(vec![
+            vec![2.0, 0.0, 0.0, 0.0],
+            vec![0.0, 3.0, 0.0, 0.0],
+            vec![0.0, 0.0, 4.0, 0.0],
+            vec![0.0, 0.0, 0.0, 5.0],
+        ])
+        .unwrap();
+
+        assert_eq!(m.determinant(), 120.0)
+    }
+
+    #[test]
+    fn inverse_of_invertible_2x2_matrix() {
+        let m = matrix![[4.0f64, 7.0], [2.0, 6.0]];
+        let inv = m.inverse().expect("matrix should be invertible");
+        let expected = matrix![[0.6, -0.7], [-0.2, 0.4]];
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((inv[(i, j)] - expected[(i, j)]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_of_singular_matrix_is_none() {
+        let m = matrix![[1.0, 2.0], [2.0, 4.0]];
+
+        assert_eq!(m.inverse(), None)
+    }
+
+    #[test]
+    fn determinant_works_for_other_division_capable_types() {
+        let m = matrix![[1.0f32, 2.0], [3.0, 4.0]];
+
+        assert_eq!(m.determinant(), -2.0f32)
+    }
 }
@@ -0,0 +1,86 @@
+use std::ops::{Add, AddAssign, Mul};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        ModInt(value % M)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<const M: u64> Default for ModInt<M> {
+    fn default() -> Self {
+        ModInt(0)
+    }
+}
+
+impl<const M: u64> From<u8> for ModInt<M> {
+    fn from(value: u8) -> Self {
+        ModInt::new(value as u64)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ModInt(((self.0 as u128 + rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        ModInt(((self.0 as u128 * rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn arithmetic_wraps_at_the_modulus() {
+        let a = ModInt::<7>::new(5);
+        let b = ModInt::<7>::new(4);
+
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((a * b).value(), 6);
+    }
+
+    #[test]
+    fn arithmetic_does_not_overflow_under_a_large_modulus() {
+        const BIG: u64 = (1 << 61) - 1;
+        let a = ModInt::<BIG>::new(BIG - 1);
+        let b = ModInt::<BIG>::new(BIG - 1);
+
+        assert_eq!((a + b).value(), BIG - 2);
+        assert_eq!((a * b).value(), 1);
+    }
+
+    #[test]
+    fn matrix_pow_under_a_modulus() {
+        let m = Matrix::try_from(vec![
+            vec![ModInt::<1000000007>::new(1), ModInt::new(1)],
+            vec![ModInt::new(1), ModInt::new(0)],
+        ])
+        .unwrap();
+
+        let fib = m.pow(10);
+
+        assert_eq!(fib[(0, 1)].value(), 55);
+    }
+}
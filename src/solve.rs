@@ -0,0 +1,109 @@
+use crate::matrix::{eliminate_column, Matrix};
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    Singular,
+    Inconsistent,
+    DimensionMismatch,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Singular => write!(f, "coefficient matrix is singular"),
+            SolveError::Inconsistent => write!(f, "system is inconsistent and has no solution"),
+            SolveError::DimensionMismatch => {
+                write!(
+                    f,
+                    "right-hand side length does not match the coefficient matrix"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+pub fn solve(a: &Matrix<f64>, b: &[f64]) -> Result<Vec<f64>, SolveError> {
+    let n = a.rows();
+    if a.cols() != n || b.len() != n {
+        return Err(SolveError::DimensionMismatch);
+    }
+
+    let mut aug: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            let mut row: Vec<f64> = (0..n).map(|j| a[(i, j)]).collect();
+            row.push(b[i]);
+            row
+        })
+        .collect();
+
+    for col in 0..n {
+        if eliminate_column(&mut aug, col, (col + 1)..n).is_none() {
+            let has_contradiction = (col..n).any(|row| {
+                let coeffs_are_zero = (col..n).all(|c| aug[row][c].abs() < f64::EPSILON);
+                coeffs_are_zero && aug[row][n].abs() > f64::EPSILON
+            });
+            return Err(if has_contradiction {
+                SolveError::Inconsistent
+            } else {
+                SolveError::Singular
+            });
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum: f64 = ((i + 1)..n).map(|j| aug[i][j] * x[j]).sum();
+        x[i] = (aug[i][n] - sum) / aug[i][i];
+    }
+
+    Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn solves_a_well_conditioned_system() {
+        let a = Matrix::try_from(vec![vec![2.0, 1.0], vec![5.0, 7.0]]).unwrap();
+        let b = [11.0, 13.0];
+
+        let x = solve(&a, &b).unwrap();
+
+        assert_close(&x, &[7.111111111111111, -3.222222222222222]);
+    }
+
+    #[test]
+    fn singular_matrix_is_an_error() {
+        let a = Matrix::try_from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        let b = [1.0, 2.0];
+
+        assert_eq!(solve(&a, &b), Err(SolveError::Singular));
+    }
+
+    #[test]
+    fn inconsistent_system_is_distinguished_from_singular() {
+        let a = Matrix::try_from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        let b = [1.0, 3.0];
+
+        assert_eq!(solve(&a, &b), Err(SolveError::Inconsistent));
+    }
+
+    #[test]
+    fn mismatched_dimensions_is_an_error() {
+        let a = Matrix::try_from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+        let b = [1.0, 2.0, 3.0];
+
+        assert_eq!(solve(&a, &b), Err(SolveError::DimensionMismatch));
+    }
+}
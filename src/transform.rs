@@ -0,0 +1,123 @@
+use crate::matrix::Matrix;
+
+impl Matrix<f64> {
+    pub fn rotation_x(theta: f64) -> Matrix<f64> {
+        let (s, c) = theta.sin_cos();
+        Matrix::try_from(vec![
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.0, c, -s, 0.0],
+            vec![0.0, s, c, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap()
+    }
+
+    pub fn rotation_y(theta: f64) -> Matrix<f64> {
+        let (s, c) = theta.sin_cos();
+        Matrix::try_from(vec![
+            vec![c, 0.0, s, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![-s, 0.0, c, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap()
+    }
+
+    pub fn rotation_z(theta: f64) -> Matrix<f64> {
+        let (s, c) = theta.sin_cos();
+        Matrix::try_from(vec![
+            vec![c, -s, 0.0, 0.0],
+            vec![s, c, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap()
+    }
+
+    pub fn translation(dx: f64, dy: f64, dz: f64) -> Matrix<f64> {
+        Matrix::try_from(vec![
+            vec![1.0, 0.0, 0.0, dx],
+            vec![0.0, 1.0, 0.0, dy],
+            vec![0.0, 0.0, 1.0, dz],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap()
+    }
+
+    pub fn perspective(fov_y: f64, aspect: f64, near: f64, far: f64) -> Matrix<f64> {
+        let f = 1.0 / (fov_y / 2.0).tan();
+        Matrix::try_from(vec![
+            vec![f / aspect, 0.0, 0.0, 0.0],
+            vec![0.0, f, 0.0, 0.0],
+            vec![
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                (2.0 * far * near) / (near - far),
+            ],
+            vec![0.0, 0.0, -1.0, 0.0],
+        ])
+        .unwrap()
+    }
+
+    pub fn apply(&self, point: [f64; 3]) -> [f64; 3] {
+        assert_eq!(
+            (self.rows(), self.cols()),
+            (4, 4),
+            "apply requires a 4x4 homogeneous transform"
+        );
+
+        let v = [point[0], point[1], point[2], 1.0];
+        let mut out = [0.0; 4];
+        for i in 0..4 {
+            out[i] = (0..4).map(|j| self[(i, j)] * v[j]).sum();
+        }
+
+        if out[3].abs() > f64::EPSILON {
+            [out[0] / out[3], out[1] / out[3], out[2] / out[3]]
+        } else {
+            [out[0], out[1], out[2]]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point_close(a: [f64; 3], b: [f64; 3]) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < 1e-9, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn translation_shifts_a_point() {
+        let t = Matrix::translation(1.0, 2.0, 3.0);
+
+        assert_point_close(t.apply([0.0, 0.0, 0.0]), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn rotation_z_by_quarter_turn_swaps_axes() {
+        let r = Matrix::rotation_z(std::f64::consts::FRAC_PI_2);
+
+        assert_point_close(r.apply([1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn composed_transform_applies_rotation_then_translation() {
+        let combined =
+            Matrix::translation(5.0, 0.0, 0.0) * Matrix::rotation_y(std::f64::consts::PI);
+
+        assert_point_close(combined.apply([1.0, 0.0, 0.0]), [4.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn perspective_maps_the_near_plane_to_ndc_z_minus_one() {
+        let p = Matrix::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+
+        assert_point_close(p.apply([0.0, 0.0, -1.0]), [0.0, 0.0, -1.0]);
+        assert_point_close(p.apply([1.0, 0.0, -1.0]), [1.0, 0.0, -1.0]);
+    }
+}